@@ -22,12 +22,10 @@ fn main() {
 }
 
 fn tick(projectile: &mut Projectile, env: &Environment) {
-    let p = projectile.clone();
-    projectile.position = p.position + p.velocity.clone();
-    projectile.velocity = p.velocity + env.gravity.clone() + env.wind.clone();
+    projectile.position += projectile.velocity.clone();
+    projectile.velocity += env.gravity.clone() + env.wind.clone();
 }
 
-#[derive(Clone)]
 struct Projectile {
     position: Point,
     velocity: Vector,
@@ -1,7 +1,7 @@
 //
 // Base types used in umbralux
 //
-use std::ops::{Add, Div, Mul, Sub};
+use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
 use anyhow::anyhow;
 
 pub type Number = f64;
@@ -31,6 +31,24 @@ impl Point {
     pub fn z(&self) -> Number {
         self.2
     }
+
+    pub fn lerp(&self, other: &Point, t: Number) -> Point {
+        Point(
+            self.0 + (other.0 - self.0) * t,
+            self.1 + (other.1 - self.1) * t,
+            self.2 + (other.2 - self.2) * t,
+        )
+    }
+
+    pub fn midpoint(&self, other: &Point) -> Point {
+        self.lerp(other, 0.5)
+    }
+
+    pub fn approx_eq(&self, other: &Point, eps: Number) -> bool {
+        (self.0 - other.0).abs() < eps &&
+            (self.1 - other.1).abs() < eps &&
+            (self.2 - other.2).abs() < eps
+    }
 }
 
 impl TryFrom<Vec4> for Point {
@@ -50,6 +68,14 @@ impl Add<Vector> for Point {
     }
 }
 
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, rhs: Vector) {
+        self.0 += rhs.0;
+        self.1 += rhs.1;
+        self.2 += rhs.2;
+    }
+}
+
 impl Sub<Point> for Point {
     type Output = Vector;
     fn sub(self, rhs: Point) -> Vector {
@@ -64,6 +90,14 @@ impl Sub<Vector> for Point {
     }
 }
 
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, rhs: Vector) {
+        self.0 -= rhs.0;
+        self.1 -= rhs.1;
+        self.2 -= rhs.2;
+    }
+}
+
 impl Mul<Number> for Point {
     type Output = Point;
     fn mul(self, rhs: Number) -> Point {
@@ -78,6 +112,40 @@ impl Div<Number> for Point {
     }
 }
 
+impl Add<&Vector> for &Point {
+    type Output = Point;
+    fn add(self, rhs: &Vector) -> Point {
+        Point(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+impl Sub<&Point> for &Point {
+    type Output = Vector;
+    fn sub(self, rhs: &Point) -> Vector {
+        Vector(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
+    }
+}
+
+impl Sub<&Vector> for &Point {
+    type Output = Point;
+    fn sub(self, rhs: &Vector) -> Point {
+        Point(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
+    }
+}
+
+impl Mul<Number> for &Point {
+    type Output = Point;
+    fn mul(self, rhs: Number) -> Point {
+        Point(self.0 * rhs, self.1 * rhs, self.2 * rhs)
+    }
+}
+
+impl Div<Number> for &Point {
+    type Output = Point;
+    fn div(self, rhs: Number) -> Point {
+        Point(self.0 / rhs, self.1 / rhs, self.2 / rhs)
+    }
+}
 
 impl PartialEq for Point {
     fn eq(&self, other: &Point) -> bool {
@@ -112,9 +180,13 @@ impl Vector {
         squares_sum.sqrt()
     }
 
+    pub fn is_unit(&self, eps: Number) -> bool {
+        (self.magnitude() - 1.0).abs() < eps
+    }
+
     pub fn normalize(&self) -> Vector {
         let magnitude = self.magnitude();
-        self.clone() / magnitude
+        self / magnitude
     }
 
     pub fn dot(&self, other: &Vector) -> Number {
@@ -128,6 +200,24 @@ impl Vector {
             self.0 * other.1 - self.1 * other.0,
         )
     }
+
+    pub fn approx_eq(&self, other: &Vector, eps: Number) -> bool {
+        (self.0 - other.0).abs() < eps &&
+            (self.1 - other.1).abs() < eps &&
+            (self.2 - other.2).abs() < eps
+    }
+
+    pub fn reflect(&self, normal: &Vector) -> Vector {
+        self - &(normal * (2.0 * self.dot(normal)))
+    }
+
+    pub fn lerp(&self, other: &Vector, t: Number) -> Vector {
+        Vector(
+            self.0 + (other.0 - self.0) * t,
+            self.1 + (other.1 - self.1) * t,
+            self.2 + (other.2 - self.2) * t,
+        )
+    }
 }
 
 impl TryFrom<Vec4> for Vector {
@@ -147,6 +237,14 @@ impl Add<Vector> for Vector {
     }
 }
 
+impl AddAssign<Vector> for Vector {
+    fn add_assign(&mut self, rhs: Vector) {
+        self.0 += rhs.0;
+        self.1 += rhs.1;
+        self.2 += rhs.2;
+    }
+}
+
 impl Sub<Vector> for Vector {
     type Output = Vector;
     fn sub(self, rhs: Vector) -> Vector {
@@ -154,6 +252,14 @@ impl Sub<Vector> for Vector {
     }
 }
 
+impl SubAssign<Vector> for Vector {
+    fn sub_assign(&mut self, rhs: Vector) {
+        self.0 -= rhs.0;
+        self.1 -= rhs.1;
+        self.2 -= rhs.2;
+    }
+}
+
 impl Mul<Number> for Vector {
     type Output = Vector;
     fn mul(self, rhs: Number) -> Vector {
@@ -168,6 +274,34 @@ impl Div<Number> for Vector {
     }
 }
 
+impl Add<&Vector> for &Vector {
+    type Output = Vector;
+    fn add(self, rhs: &Vector) -> Vector {
+        Vector(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+impl Sub<&Vector> for &Vector {
+    type Output = Vector;
+    fn sub(self, rhs: &Vector) -> Vector {
+        Vector(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
+    }
+}
+
+impl Mul<Number> for &Vector {
+    type Output = Vector;
+    fn mul(self, rhs: Number) -> Vector {
+        Vector(self.0 * rhs, self.1 * rhs, self.2 * rhs)
+    }
+}
+
+impl Div<Number> for &Vector {
+    type Output = Vector;
+    fn div(self, rhs: Number) -> Vector {
+        Vector(self.0 / rhs, self.1 / rhs, self.2 / rhs)
+    }
+}
+
 impl PartialEq for Vector {
     fn eq(&self, other: &Vector) -> bool {
         is_number_equal(self.0, other.0) &&
@@ -236,6 +370,24 @@ mod tests {
         assert_eq!(p1, p2 - v);
     }
 
+    #[test]
+    fn can_add_assign_vector_to_point() {
+        let mut p = Point::new(1.0, 2.0, 3.0);
+        let v = Vector::new(1.0, 0.0, -1.0);
+        let expected = p.clone() + v.clone();
+        p += v;
+        assert_eq!(expected, p);
+    }
+
+    #[test]
+    fn can_sub_assign_vector_from_point() {
+        let mut p = Point::new(1.0, 2.0, 3.0);
+        let v = Vector::new(1.0, 0.0, -1.0);
+        let expected = p.clone() - v.clone();
+        p -= v;
+        assert_eq!(expected, p);
+    }
+
     #[test]
     fn can_create_vector() {
         let v = Vec4::from(Vector::new(1.0, 2.0, 3.0));
@@ -259,6 +411,46 @@ mod tests {
         assert_eq!(expected, v1 - v2);
     }
 
+    #[test]
+    fn can_add_assign_vectors() {
+        let mut v1 = Vector::new(1.0, 2.0, 3.0);
+        let v2 = Vector::new(2.0, 3.0, 4.0);
+        let expected = v1.clone() + v2.clone();
+        v1 += v2;
+        assert_eq!(expected, v1);
+    }
+
+    #[test]
+    fn can_sub_assign_vectors() {
+        let mut v1 = Vector::new(1.0, 2.0, 3.0);
+        let v2 = Vector::new(2.0, 3.0, 4.0);
+        let expected = v1.clone() - v2.clone();
+        v1 -= v2;
+        assert_eq!(expected, v1);
+    }
+
+    #[test]
+    fn can_operate_on_borrowed_points() {
+        let p1 = Point::new(1.0, 2.0, 3.0);
+        let p2 = Point::new(2.0, 2.0, 2.0);
+        let v = Vector::new(1.0, 0.0, -1.0);
+        assert_eq!(p1.clone() + v.clone(), &p1 + &v);
+        assert_eq!(p2.clone() - p1.clone(), &p2 - &p1);
+        assert_eq!(p1.clone() - v.clone(), &p1 - &v);
+        assert_eq!(p1.clone() * 2.0, &p1 * 2.0);
+        assert_eq!(p1.clone() / 2.0, &p1 / 2.0);
+    }
+
+    #[test]
+    fn can_operate_on_borrowed_vectors() {
+        let v1 = Vector::new(1.0, 2.0, 3.0);
+        let v2 = Vector::new(2.0, 3.0, 4.0);
+        assert_eq!(v1.clone() + v2.clone(), &v1 + &v2);
+        assert_eq!(v1.clone() - v2.clone(), &v1 - &v2);
+        assert_eq!(v1.clone() * 2.0, &v1 * 2.0);
+        assert_eq!(v1.clone() / 2.0, &v1 / 2.0);
+    }
+
     #[test]
     fn can_normalize_vector() {
         let v = Vector::new(3.0, 4.0, 0.0);
@@ -266,6 +458,12 @@ mod tests {
         assert_eq!(v_norm, v.normalize());
     }
 
+    #[test]
+    fn can_check_for_unit_vector() {
+        assert!(Vector::new(1.0, 0.0, 0.0).is_unit(1e-6));
+        assert!(!Vector::new(2.0, 0.0, 0.0).is_unit(1e-6));
+    }
+
     #[test]
     fn two_perpendicular_vectors_have_zero_dot_product() {
         let v1 = Vector::new(1.0, 2.0, 4.0);
@@ -283,4 +481,52 @@ mod tests {
         assert_eq!(y, z.cross(&x));
     }
 
+    #[test]
+    fn can_reflect_vector() {
+        let v = Vector::new(1.0, -1.0, 0.0);
+        let n = Vector::new(0.0, 1.0, 0.0);
+        assert_eq!(Vector::new(1.0, 1.0, 0.0), v.reflect(&n));
+    }
+
+    #[test]
+    fn can_compare_points_approximately() {
+        let p1 = Point::new(1.0, 2.0, 3.0);
+        let p2 = Point::new(1.0005, 2.0, 2.9995);
+        assert!(p1.approx_eq(&p2, 1e-3));
+        assert!(!p1.approx_eq(&p2, 1e-4));
+    }
+
+    #[test]
+    fn can_compare_vectors_approximately() {
+        let v1 = Vector::new(1.0, 0.0, 0.0);
+        let v2 = Vector::new(1.0, 0.0005, 0.0);
+        assert!(v1.approx_eq(&v2, 1e-3));
+        assert!(!v1.approx_eq(&v2, 1e-4));
+    }
+
+    #[test]
+    fn can_lerp_points() {
+        let p1 = Point::new(0.0, 2.0, -4.0);
+        let p2 = Point::new(2.0, 4.0, 4.0);
+        assert_eq!(p1, p1.lerp(&p2, 0.0));
+        assert_eq!(Point::new(1.0, 3.0, 0.0), p1.lerp(&p2, 0.5));
+        assert_eq!(p2, p1.lerp(&p2, 1.0));
+    }
+
+    #[test]
+    fn can_lerp_vectors() {
+        let v1 = Vector::new(1.0, 0.0, 0.0);
+        let v2 = Vector::new(0.0, 1.0, 2.0);
+        assert_eq!(v1, v1.lerp(&v2, 0.0));
+        assert_eq!(Vector::new(0.5, 0.5, 1.0), v1.lerp(&v2, 0.5));
+        assert_eq!(v2, v1.lerp(&v2, 1.0));
+    }
+
+    #[test]
+    fn can_compute_midpoint() {
+        let p1 = Point::new(-1.0, 0.0, 3.0);
+        let p2 = Point::new(3.0, 2.0, 5.0);
+        assert_eq!(Point::new(1.0, 1.0, 4.0), p1.midpoint(&p2));
+    }
+
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 use std::ops::{Add, Mul, Sub};
+use anyhow::anyhow;
 use crate::core::{Number, is_number_equal};
 
 #[derive(Debug, Clone)]
@@ -24,6 +25,20 @@ impl Color {
     pub fn blue(&self) -> Number {
         self.blue
     }
+
+    pub fn lerp(&self, other: &Color, t: Number) -> Color {
+        Color::new(
+            self.red + (other.red - self.red) * t,
+            self.green + (other.green - self.green) * t,
+            self.blue + (other.blue - self.blue) * t,
+        )
+    }
+
+    pub fn approx_eq(&self, other: &Color, eps: Number) -> bool {
+        (self.red - other.red).abs() < eps &&
+            (self.green - other.green).abs() < eps &&
+            (self.blue - other.blue).abs() < eps
+    }
 }
 
 impl Add<Color> for Color {
@@ -54,6 +69,34 @@ impl Mul<Color> for Color {
     }
 }
 
+impl Add<&Color> for &Color {
+    type Output = Color;
+    fn add(self, rhs: &Color) -> Self::Output {
+        Color::new(self.red + rhs.red, self.green + rhs.green, self.blue + rhs.blue)
+    }
+}
+
+impl Sub<&Color> for &Color {
+    type Output = Color;
+    fn sub(self, rhs: &Color) -> Self::Output {
+        Color::new(self.red - rhs.red, self.green - rhs.green, self.blue - rhs.blue)
+    }
+}
+
+impl Mul<f64> for &Color {
+    type Output = Color;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Color::new(self.red * rhs, self.green * rhs, self.blue * rhs)
+    }
+}
+
+impl Mul<&Color> for &Color {
+    type Output = Color;
+    fn mul(self, rhs: &Color) -> Self::Output {
+        Color::new(self.red * rhs.red, self.green * rhs.green, self.blue * rhs.blue)
+    }
+}
+
 impl PartialEq for Color {
     fn eq(&self, other: &Self) -> bool {
         is_number_equal(self.red, other.red) &&
@@ -62,6 +105,42 @@ impl PartialEq for Color {
     }
 }
 
+// Color ramp defined by stops (position, color) with positions
+// typically in the range 0..1
+#[derive(Debug, Clone)]
+pub struct Palette {
+    stops: Vec<(Number, Color)>,
+}
+
+impl Palette {
+    pub fn new(mut stops: Vec<(Number, Color)>) -> anyhow::Result<Palette> {
+        if stops.is_empty() {
+            return Err(anyhow!("Palette requires at least one color stop"));
+        }
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Ok(Self { stops })
+    }
+
+    pub fn sample(&self, t: Number) -> Color {
+        let (first_pos, first_color) = &self.stops[0];
+        if t <= *first_pos {
+            return first_color.clone();
+        }
+        for window in self.stops.windows(2) {
+            let (pos_a, color_a) = &window[0];
+            let (pos_b, color_b) = &window[1];
+            if t <= *pos_b {
+                let width = pos_b - pos_a;
+                if width <= 0.0 {
+                    return color_b.clone();
+                }
+                return color_a.lerp(color_b, (t - pos_a) / width);
+            }
+        }
+        self.stops[self.stops.len() - 1].1.clone()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -110,4 +189,54 @@ mod tests {
         assert_eq!(c2, Color::new(1.8, 1.2, 1.5));
     }
 
+    #[test]
+    fn test_borrowed_ops() {
+        let c1 = Color::new(0.9, 0.6, 0.75);
+        let c2 = Color::new(0.7, 0.1, 0.25);
+
+        assert_eq!(&c1 + &c2, c1.clone() + c2.clone());
+        assert_eq!(&c1 - &c2, c1.clone() - c2.clone());
+        assert_eq!(&c1 * &c2, c1.clone() * c2.clone());
+        assert_eq!(&c1 * 2.0, c1.clone() * 2.0);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let c1 = Color::new(1.0, 0.0, 0.0);
+        let c2 = Color::new(0.0, 0.0, 1.0);
+
+        assert_eq!(c1.lerp(&c2, 0.0), c1);
+        assert_eq!(c1.lerp(&c2, 0.5), Color::new(0.5, 0.0, 0.5));
+        assert_eq!(c1.lerp(&c2, 1.0), c2);
+    }
+
+    #[test]
+    fn test_palette_sample() {
+        let palette = Palette::new(vec![
+            (1.0, Color::new(0.0, 0.0, 1.0)),
+            (0.0, Color::new(1.0, 0.0, 0.0)),
+            (0.5, Color::new(0.0, 1.0, 0.0)),
+        ]).unwrap();
+
+        assert_eq!(palette.sample(-1.0), Color::new(1.0, 0.0, 0.0));
+        assert_eq!(palette.sample(0.25), Color::new(0.5, 0.5, 0.0));
+        assert_eq!(palette.sample(0.5), Color::new(0.0, 1.0, 0.0));
+        assert_eq!(palette.sample(0.75), Color::new(0.0, 0.5, 0.5));
+        assert_eq!(palette.sample(2.0), Color::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_empty_palette() {
+        assert!(Palette::new(vec![]).is_err());
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let c1 = Color::new(0.5, 0.5, 0.5);
+        let c2 = Color::new(0.5, 0.5005, 0.5);
+
+        assert!(c1.approx_eq(&c2, 1e-3));
+        assert!(!c1.approx_eq(&c2, 1e-4));
+    }
+
 }
@@ -2,4 +2,4 @@ mod base_types;
 mod color;
 
 pub use base_types::{Point, Vector, Number, is_number_equal};
-pub use color::Color;
+pub use color::{Color, Palette};
@@ -0,0 +1,68 @@
+//
+// Optics functions (reflection and refraction) used in umbralux
+//
+use crate::core::{Number, Vector};
+
+const UNIT_TOLERANCE: Number = 1e-6;
+
+pub fn reflect(incoming: &Vector, normal: &Vector) -> Vector {
+    debug_assert!(normal.is_unit(UNIT_TOLERANCE), "normal must be a unit vector");
+    incoming.reflect(normal)
+}
+
+// Refracts the incoming direction at a surface with the given normal, where
+// n_ratio is the ratio n1/n2 of the refractive indices. Returns None in case
+// of total internal reflection.
+pub fn refract(incoming: &Vector, normal: &Vector, n_ratio: Number) -> Option<Vector> {
+    debug_assert!(incoming.is_unit(UNIT_TOLERANCE), "incoming must be a unit vector");
+    debug_assert!(normal.is_unit(UNIT_TOLERANCE), "normal must be a unit vector");
+    let cos_i = -incoming.dot(normal);
+    let sin2_t = n_ratio * n_ratio * (1.0 - cos_i * cos_i);
+    if sin2_t > 1.0 {
+        return None;
+    }
+    let cos_t = (1.0 - sin2_t).sqrt();
+    Some(&(normal * (n_ratio * cos_i - cos_t)) + &(incoming * n_ratio))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: Number = 1e-10;
+
+    fn is_close(a: Number, b: Number) -> bool {
+        (a - b).abs() < TOLERANCE
+    }
+
+    #[test]
+    fn can_reflect_off_slanted_surface() {
+        let v = Vector::new(0.0, -1.0, 0.0);
+        let sqrt2_half = 2.0_f64.sqrt() / 2.0;
+        let n = Vector::new(sqrt2_half, sqrt2_half, 0.0);
+        let r = reflect(&v, &n);
+        assert!(is_close(r.x(), 1.0));
+        assert!(is_close(r.y(), 0.0));
+        assert!(is_close(r.z(), 0.0));
+    }
+
+    #[test]
+    fn can_refract_into_denser_medium() {
+        let incoming = Vector::new(1.0, -1.0, 0.0).normalize();
+        let n = Vector::new(0.0, 1.0, 0.0);
+        let r = refract(&incoming, &n, 1.0 / 1.5).unwrap();
+        let sin_i = incoming.x();
+        assert!(is_close(r.x(), sin_i / 1.5));
+        assert!(r.y() < 0.0);
+        assert!(is_close(r.z(), 0.0));
+        assert!(is_close(r.magnitude(), 1.0));
+    }
+
+    #[test]
+    fn total_internal_reflection_yields_none() {
+        let incoming = Vector::new(1.0, -1.0, 0.0).normalize();
+        let n = Vector::new(0.0, 1.0, 0.0);
+        assert!(refract(&incoming, &n, 1.5).is_none());
+    }
+
+}
@@ -1 +1,2 @@
-pub mod core;
\ No newline at end of file
+pub mod core;
+pub mod features;
\ No newline at end of file